@@ -1,26 +1,65 @@
 use crate::make_selector;
 use crate::parse_int;
 use eyre::{eyre, Result};
+use serde::Serialize;
 
-#[derive(Debug)]
-#[allow(dead_code)]
+#[derive(Debug, Serialize)]
 pub struct Book {
     title: String,
     upc: String,
-    price: String,
+    price: Price,
     available: u32,
     reviews: u32,
     rating: u8,
+    description: Option<String>,
+}
+
+/// A price split into its numeric amount and a currency: an ISO-4217 code for
+/// recognised symbols, otherwise the raw symbol.
+#[derive(Debug, Serialize)]
+pub struct Price {
+    amount: f64,
+    currency: String,
+}
+
+impl Price {
+    /// Splits a rendered price such as `"£51.77"` into its amount and the
+    /// ISO-4217 code of its leading currency symbol, falling back to the raw
+    /// symbol for anything we don't recognise.
+    fn parse(text: &str) -> Result<Self> {
+        let symbol: String = text
+            .chars()
+            .take_while(|c| !c.is_ascii_digit() && *c != '.')
+            .collect();
+        let amount = text
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '.')
+            .collect::<String>()
+            .parse::<f64>()
+            .map_err(|e| eyre!("Failed to parse price amount from {text:?}: {e}"))?;
+
+        let currency = match symbol.trim() {
+            "£" => "GBP",
+            "$" => "USD",
+            "€" => "EUR",
+            "¥" => "JPY",
+            other => other,
+        }
+        .to_string();
+
+        Ok(Self { amount, currency })
+    }
 }
 
 impl Book {
     const fn new(
         title: String,
         upc: String,
-        price: String,
+        price: Price,
         available: u32,
         reviews: u32,
         rating: u8,
+        description: Option<String>,
     ) -> Self {
         Self {
             title,
@@ -29,6 +68,7 @@ impl Book {
             available,
             reviews,
             rating,
+            description,
         }
     }
     pub fn from_html(page: &scraper::Html) -> Result<Self> {
@@ -39,9 +79,28 @@ impl Book {
             Self::extract_available(page)?,
             Self::extract_reviews(page)?,
             Self::extract_rating(page)?,
+            Self::extract_description(page),
         ))
     }
 
+    /// Pulls the product description from the paragraph right after
+    /// `#product_description`. A book without that block genuinely has no
+    /// description, so we return `None` rather than letting the generic
+    /// content scorer pick up unrelated page text; the scorer is for
+    /// extractors that lack such a selector.
+    fn extract_description(book_page: &scraper::Html) -> Option<String> {
+        let sel = make_selector("#product_description + p");
+        let text = book_page
+            .select(&sel)
+            .next()?
+            .text()
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!text.is_empty()).then_some(text)
+    }
+
     fn extract_title(book_page: &scraper::Html) -> Result<String> {
         let sel = make_selector("div[class$='product_main'] h1");
         book_page.select(&sel).next().map_or_else(
@@ -64,15 +123,16 @@ impl Book {
         )
     }
 
-    fn extract_price(book_page: &scraper::Html) -> Result<String> {
+    fn extract_price(book_page: &scraper::Html) -> Result<Price> {
         let sel = make_selector("div[class$='product_main']  p[class^='price']");
-        book_page.select(&sel).next().map_or_else(
+        let text: String = book_page.select(&sel).next().map_or_else(
             || {
                 log::warn!("Failed to extract price from book page");
                 Err(eyre!("Failed to extract price from book page"))
             },
             |elem| Ok(elem.text().collect()),
-        )
+        )?;
+        Price::parse(&text)
     }
 
     fn extract_available(book_page: &scraper::Html) -> Result<u32> {
@@ -115,3 +175,33 @@ impl Book {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_parse_known_symbols() -> Result<()> {
+        let gbp = Price::parse("£51.77")?;
+        assert_eq!(gbp.amount, 51.77);
+        assert_eq!(gbp.currency, "GBP");
+
+        assert_eq!(Price::parse("$3.00")?.currency, "USD");
+        assert_eq!(Price::parse("€10.50")?.currency, "EUR");
+        assert_eq!(Price::parse("¥900")?.currency, "JPY");
+        Ok(())
+    }
+
+    #[test]
+    fn test_price_parse_unknown_symbol_falls_back() -> Result<()> {
+        let price = Price::parse("R$ 12.34")?;
+        assert_eq!(price.amount, 12.34);
+        assert_eq!(price.currency, "R$");
+        Ok(())
+    }
+
+    #[test]
+    fn test_price_parse_rejects_missing_amount() {
+        assert!(Price::parse("free").is_err());
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use url::Url;
+
+/// Top-level scrape output: the site metadata wrapping the scraped items.
+///
+/// Named after the book catalogue this crate grew out of, but the items are
+/// whatever the matched [`crate::extractors::Extractor`] produced, so the
+/// shape works for any site.
+#[derive(Debug, Serialize)]
+pub struct Catalogue {
+    pub base_url: Url,
+    pub scraped_at: DateTime<Utc>,
+    pub total: usize,
+    pub items: Vec<serde_json::Value>,
+}
+
+impl Catalogue {
+    /// Stamps the current time and records the item count.
+    #[must_use]
+    pub fn new(base_url: Url, items: Vec<serde_json::Value>) -> Self {
+        Self {
+            base_url,
+            scraped_at: Utc::now(),
+            total: items.len(),
+            items,
+        }
+    }
+}
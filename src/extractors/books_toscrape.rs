@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use eyre::{eyre, Result};
+use url::Url;
+
+use super::Extractor;
+use crate::book::Book;
+use crate::fetch::Session;
+use crate::make_selector;
+
+/// Extractor for <https://books.toscrape.com>, the demo catalogue this crate
+/// was originally written against.
+pub struct BooksToScrapeExtractor;
+
+impl BooksToScrapeExtractor {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait(?Send)]
+impl Extractor for BooksToScrapeExtractor {
+    fn matches(&self, url: &Url) -> bool {
+        url.host_str() == Some("books.toscrape.com")
+    }
+
+    async fn list_item_urls(&self, session: &Session, root: &Url) -> Result<Vec<Url>> {
+        let book_url_selector = make_selector("article.product_pod a[title]");
+        let next_selector = make_selector("li.next a");
+
+        // Crawl outward from the caller's start URL, following the "next" link
+        // from one catalogue page to the next instead of guessing a page
+        // count. A missing `li.next` is a clean end of pagination; any error
+        // fetching a page (a transient failure, or a 404 on a page we were
+        // told exists) propagates rather than being silently treated as the
+        // end.
+        let mut urls = Vec::new();
+        let mut current = Some(root.clone());
+
+        while let Some(page_url) = current.take() {
+            let page = session.get_html(page_url.clone()).await?;
+
+            urls.extend(
+                page.select(&book_url_selector)
+                    .filter_map(|d| d.value().attr("href"))
+                    .filter_map(|href| page_url.join(href).ok()),
+            );
+
+            if let Some(href) = page
+                .select(&next_selector)
+                .next()
+                .and_then(|e| e.value().attr("href"))
+            {
+                current = Some(page_url.join(href)?);
+            }
+        }
+
+        Ok(urls)
+    }
+
+    fn parse_item(&self, page: &scraper::Html) -> Result<serde_json::Value> {
+        let book = Book::from_html(page)?;
+        serde_json::to_value(book).map_err(|e| eyre!("Failed to serialize book: {e}"))
+    }
+}
@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use eyre::Result;
+use url::Url;
+
+use crate::fetch::Session;
+
+pub mod books_toscrape;
+
+use books_toscrape::BooksToScrapeExtractor;
+
+/// A per-site scraper, in the spirit of a yt-dlp extractor: it claims the URLs
+/// it understands, enumerates the individual item pages reachable from a root,
+/// and turns a single parsed page into structured JSON.
+///
+/// `?Send` because parsing leans on `scraper::Html`, whose `Rc`-backed nodes
+/// are not `Send`; the crawl is driven from a single `block_on`, so the
+/// futures never cross threads.
+#[async_trait(?Send)]
+pub trait Extractor: Send + Sync {
+    /// Returns `true` if this extractor knows how to handle `url`.
+    fn matches(&self, url: &Url) -> bool;
+
+    /// Walks `root` over `session` and returns the URLs of every item page to
+    /// scrape.
+    async fn list_item_urls(&self, session: &Session, root: &Url) -> Result<Vec<Url>>;
+
+    /// Parses one already-fetched item page into a JSON value.
+    fn parse_item(&self, page: &scraper::Html) -> Result<serde_json::Value>;
+}
+
+/// Holds the known extractors and picks one by matching the input URL.
+pub struct Registry {
+    extractors: Vec<Box<dyn Extractor>>,
+}
+
+impl Registry {
+    /// Builds a registry containing every built-in extractor.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            extractors: vec![Box::new(BooksToScrapeExtractor::new())],
+        }
+    }
+
+    /// Returns the first registered extractor that claims `url`, if any.
+    #[must_use]
+    pub fn find(&self, url: &Url) -> Option<&dyn Extractor> {
+        self.extractors
+            .iter()
+            .find(|e| e.matches(url))
+            .map(AsRef::as_ref)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,142 @@
+use std::time::{Duration, Instant};
+
+use eyre::{eyre, Result};
+use reqwest::StatusCode;
+use tokio::sync::Mutex;
+use url::Url;
+
+/// User-agent sent with every request so sites can identify the crawler.
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Minimum-interval throttle shared across the concurrent fan-out so the
+/// crawler honours a requests-per-second cap regardless of how many fetches
+/// are in flight.
+struct RateLimiter {
+    interval: Duration,
+    last: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: f64) -> Self {
+        let interval = if requests_per_second > 0.0 {
+            Duration::from_secs_f64(1.0 / requests_per_second)
+        } else {
+            Duration::ZERO
+        };
+        Self {
+            interval,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Waits until enough time has elapsed since the previous request.
+    async fn acquire(&self) {
+        if self.interval.is_zero() {
+            return;
+        }
+        let mut last = self.last.lock().await;
+        if let Some(prev) = *last {
+            let elapsed = prev.elapsed();
+            if elapsed < self.interval {
+                tokio::time::sleep(self.interval - elapsed).await;
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+/// A reusable HTTP session: one `reqwest::Client` with a cookie jar, a polite
+/// rate limit, and retry-with-backoff on transient failures. Share one across
+/// the whole crawl so cookies and throttling apply everywhere.
+pub struct Session {
+    client: reqwest::Client,
+    limiter: RateLimiter,
+    max_concurrency: usize,
+    retries: u32,
+}
+
+impl Session {
+    /// Builds a session. `requests_per_second` of `0.0` disables throttling;
+    /// `retries` is the number of *extra* attempts after the first on a
+    /// 429/5xx or transport error.
+    pub fn new(max_concurrency: usize, requests_per_second: f64, retries: u32) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .cookie_store(true)
+            .build()?;
+        Ok(Self {
+            client,
+            limiter: RateLimiter::new(requests_per_second),
+            max_concurrency,
+            retries,
+        })
+    }
+
+    /// How many fetches the caller should keep in flight at once.
+    #[must_use]
+    pub const fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    pub async fn get_page(&self, url: Url) -> Result<String> {
+        log::info!("Making GET request to: {}", url);
+        let mut attempt = 0;
+        loop {
+            self.limiter.acquire().await;
+            match self.client.get(url.clone()).send().await {
+                Ok(resp) if resp.status().is_success() => return Ok(resp.text().await?),
+                Ok(resp) if is_transient(resp.status()) && attempt < self.retries => {
+                    let delay = retry_after(&resp).unwrap_or_else(|| backoff(attempt));
+                    log::warn!(
+                        "Got {} for {}, retrying in {:?} (attempt {})",
+                        resp.status().as_u16(),
+                        url,
+                        delay,
+                        attempt + 1
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Ok(resp) => {
+                    return Err(eyre!(
+                        "Received non success status code: {}",
+                        resp.status().as_u16()
+                    ));
+                }
+                Err(err) if attempt < self.retries => {
+                    let delay = backoff(attempt);
+                    log::warn!("Request to {} failed ({}), retrying in {:?}", url, err, delay);
+                    tokio::time::sleep(delay).await;
+                }
+                Err(err) => return Err(err.into()),
+            }
+            attempt += 1;
+        }
+    }
+
+    pub async fn get_html(&self, url: Url) -> Result<scraper::Html> {
+        let resp_text = self.get_page(url).await?;
+        Ok(scraper::Html::parse_document(&resp_text))
+    }
+}
+
+/// 429 and 5xx responses are worth retrying; other statuses are not.
+fn is_transient(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Honours a `Retry-After` header expressed in whole seconds, if present.
+fn retry_after(resp: &reqwest::Response) -> Option<Duration> {
+    resp.headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Exponential backoff: 500ms, 1s, 2s, ...
+fn backoff(attempt: u32) -> Duration {
+    Duration::from_millis(500 * 2u64.pow(attempt))
+}
@@ -0,0 +1,129 @@
+use crate::make_selector;
+
+/// Block elements that never hold the main content and should be ignored as
+/// candidates, when they wrap a candidate, and when nested inside one.
+const BOILERPLATE: [&str; 6] = ["nav", "header", "footer", "aside", "script", "style"];
+
+/// Picks the dominant content block of a page, readability-style.
+///
+/// Every block candidate is scored by how much real prose it holds: long,
+/// comma-rich text counts for more, while a high ratio of link text to total
+/// text (navigation, related-links) counts for less. Boilerplate regions are
+/// skipped entirely. Returns the cleaned plain text of the best block, or
+/// `None` if the page has no meaningful content. Kept site-agnostic so other
+/// extractors can reuse it.
+#[must_use]
+#[allow(dead_code)] // reusable helper for future selector-less extractors
+pub fn extract_content(page: &scraper::Html) -> Option<String> {
+    let candidate_selector = make_selector("p, article, section, div");
+    let link_selector = make_selector("a");
+
+    let mut best: Option<(f64, String)> = None;
+    for element in page.select(&candidate_selector) {
+        if is_boilerplate(element.value().name()) {
+            continue;
+        }
+        if element.ancestors().any(|node| {
+            node.value()
+                .as_element()
+                .is_some_and(|e| is_boilerplate(e.name()))
+        }) {
+            continue;
+        }
+
+        // Text of the candidate with any nested boilerplate left out.
+        let text: String = element
+            .descendants()
+            .filter(|node| {
+                !node.ancestors().any(|a| {
+                    a.value()
+                        .as_element()
+                        .is_some_and(|e| is_boilerplate(e.name()))
+                })
+            })
+            .filter_map(|node| node.value().as_text().map(|t| &**t))
+            .collect();
+        let total = visible_len(&text);
+        if total == 0 {
+            continue;
+        }
+
+        let link_len: usize = element
+            .select(&link_selector)
+            .filter(|a| {
+                !a.ancestors().any(|node| {
+                    node.value()
+                        .as_element()
+                        .is_some_and(|e| is_boilerplate(e.name()))
+                })
+            })
+            .flat_map(|a| a.text())
+            .map(visible_len)
+            .sum();
+        let link_density = link_len as f64 / total as f64;
+        let commas = text.matches(',').count();
+
+        // Prefer long, comma-rich prose; penalise link-heavy blocks.
+        let score = total as f64 * (1.0 - link_density) + commas as f64 * 5.0;
+        if best.as_ref().is_none_or(|(b, _)| score > *b) {
+            best = Some((score, normalize(&text)));
+        }
+    }
+
+    best.map(|(_, text)| text)
+}
+
+fn is_boilerplate(tag: &str) -> bool {
+    BOILERPLATE.contains(&tag)
+}
+
+/// Number of non-whitespace characters, a rough proxy for text weight.
+fn visible_len(text: &str) -> usize {
+    text.chars().filter(|c| !c.is_whitespace()).count()
+}
+
+/// Collapses runs of whitespace into single spaces and trims the ends.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_picks_densest_block_over_boilerplate() {
+        let html = scraper::Html::parse_document(
+            r#"<html><body>
+                <nav><a href="/a">Home</a> <a href="/b">Catalogue</a></nav>
+                <p>Short.</p>
+                <article>The quick brown fox, having jumped the lazy dog,
+                 went on, at length, to describe its adventures in detail.</article>
+                <footer><a href="/c">Privacy</a></footer>
+            </body></html>"#,
+        );
+        let content = extract_content(&html).unwrap();
+        assert!(content.starts_with("The quick brown fox"));
+        assert!(!content.contains("Home"));
+    }
+
+    #[test]
+    fn test_penalises_link_heavy_blocks() {
+        let html = scraper::Html::parse_document(
+            r#"<html><body>
+                <div><a href="/1">one</a> <a href="/2">two</a> <a href="/3">three</a></div>
+                <p>A genuine, readable sentence, with several commas, wins.</p>
+            </body></html>"#,
+        );
+        assert_eq!(
+            extract_content(&html).unwrap(),
+            "A genuine, readable sentence, with several commas, wins."
+        );
+    }
+
+    #[test]
+    fn test_returns_none_without_content() {
+        let html = scraper::Html::parse_document("<html><body></body></html>");
+        assert!(extract_content(&html).is_none());
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+use futures::{stream, StreamExt};
+use serde::Serialize;
+use url::Url;
+
+use crate::fetch::Session;
+use crate::make_selector;
+
+/// What went wrong with a particular link or document.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// The link did not resolve to a 2xx response after following redirects.
+    BrokenLink,
+    /// A document declares the same `id` on more than one element.
+    DuplicateId,
+    /// An in-page `#fragment` anchor points at an `id` that does not exist.
+    DanglingFragment,
+}
+
+/// One problem found while crawling, ready to serialize into the report.
+#[derive(Debug, Serialize)]
+pub struct Finding {
+    source_url: String,
+    target_url: Option<String>,
+    kind: FailureKind,
+    detail: Option<String>,
+}
+
+/// Checks every url for reachability and, on the pages that load, for
+/// duplicate ids and dangling in-page fragment anchors. The findings are
+/// collected into a single report rather than failing the crawl.
+pub async fn check_links(session: &Session, urls: Vec<Url>) -> Vec<Finding> {
+    let reports: Vec<Vec<Finding>> = stream::iter(urls)
+        .map(|url| async move {
+            match session.get_html(url.clone()).await {
+                Ok(page) => check_page(&url, &page),
+                Err(err) => vec![Finding {
+                    source_url: url.to_string(),
+                    target_url: Some(url.to_string()),
+                    kind: FailureKind::BrokenLink,
+                    detail: Some(err.to_string()),
+                }],
+            }
+        })
+        .buffered(session.max_concurrency())
+        .collect()
+        .await;
+
+    reports.into_iter().flatten().collect()
+}
+
+/// Inspects a single parsed document for duplicate ids and dangling fragments.
+fn check_page(source: &Url, page: &scraper::Html) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let id_selector = make_selector("[id]");
+    let mut ids = HashSet::new();
+    for elem in page.select(&id_selector) {
+        if let Some(id) = elem.value().attr("id") {
+            if !ids.insert(id.to_string()) {
+                findings.push(Finding {
+                    source_url: source.to_string(),
+                    target_url: Some(format!("#{id}")),
+                    kind: FailureKind::DuplicateId,
+                    detail: None,
+                });
+            }
+        }
+    }
+
+    let anchor_selector = make_selector("a[href]");
+    for anchor in page.select(&anchor_selector) {
+        let Some(fragment) = anchor
+            .value()
+            .attr("href")
+            .and_then(|href| href.strip_prefix('#'))
+        else {
+            continue;
+        };
+        if !fragment.is_empty() && !ids.contains(fragment) {
+            findings.push(Finding {
+                source_url: source.to_string(),
+                target_url: Some(format!("#{fragment}")),
+                kind: FailureKind::DanglingFragment,
+                detail: None,
+            });
+        }
+    }
+
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(body: &str) -> scraper::Html {
+        scraper::Html::parse_document(&format!("<html><body>{body}</body></html>"))
+    }
+
+    fn source() -> Url {
+        Url::parse("https://example.com/page.html").unwrap()
+    }
+
+    #[test]
+    fn test_clean_page_has_no_findings() {
+        let html = page(r#"<h2 id="top">Title</h2><a href="#top">back to top</a>"#);
+        assert!(check_page(&source(), &html).is_empty());
+    }
+
+    #[test]
+    fn test_flags_dangling_fragment() {
+        let html = page(r#"<a href="#missing">go</a>"#);
+        let findings = check_page(&source(), &html);
+        assert_eq!(findings.len(), 1);
+        assert!(matches!(findings[0].kind, FailureKind::DanglingFragment));
+        assert_eq!(findings[0].target_url.as_deref(), Some("#missing"));
+    }
+
+    #[test]
+    fn test_flags_duplicate_id() {
+        let html = page(r#"<div id="dup"></div><div id="dup"></div>"#);
+        let findings = check_page(&source(), &html);
+        assert_eq!(findings.len(), 1);
+        assert!(matches!(findings[0].kind, FailureKind::DuplicateId));
+        assert_eq!(findings[0].target_url.as_deref(), Some("#dup"));
+    }
+
+    #[test]
+    fn test_ignores_external_and_empty_anchors() {
+        let html = page(r#"<a href="https://other.test/x">out</a><a href="#">noop</a>"#);
+        assert!(check_page(&source(), &html).is_empty());
+    }
+}